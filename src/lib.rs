@@ -3,16 +3,60 @@ use reqwest::{Client as ReqwestClient, Method};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+pub mod payout;
+pub mod poll;
+pub mod webhook;
+
 // --- Константы ---
 const CRYPTOMUS_API_BASE_URL: &str = "https://api.cryptomus.com/v1/";
 const MERCHANT_HEADER: &str = "merchant";
 const SIGN_HEADER: &str = "sign";
 
-pub type CryptomusError = Box<dyn std::error::Error + Send + Sync>;
+// --- Ошибки ---
+
+// Структурированная ошибка клиента Cryptomus.
+// state != 0 в ответе API попадает в вариант `Api` вместе с сообщением и
+// деталями валидации, поэтому вызывающий код может сопоставлять конкретные
+// ошибки вместо разбора строк.
+#[derive(thiserror::Error, Debug)]
+pub enum CryptomusError {
+    // Ошибка, возвращенная API Cryptomus (state != 0)
+    #[error("cryptomus api error (state {state}): {message:?}")]
+    Api {
+        state: i64,
+        message: Option<String>,
+        errors: Option<serde_json::Value>,
+    },
+    // Ошибка транспортного уровня (reqwest)
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    // Ошибка (де)сериализации JSON
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    // Некорректное значение HTTP-заголовка (merchant_id или sign)
+    #[error(transparent)]
+    Header(#[from] reqwest::header::InvalidHeaderValue),
+    // Не задан API ключ, необходимый для подписи запроса
+    #[error("api key is missing")]
+    MissingApiKey,
+    // Ответ с state == 0, но без поля result
+    #[error("response did not contain a result")]
+    MissingResult,
+    // Подпись входящего вебхука не совпала с пересчитанной
+    #[error("webhook signature mismatch")]
+    SignatureMismatch,
+    // Некорректный запрос, отклоненный до отправки; также неразбираемое тело
+    // ответа с HTTP-ошибкой и некорректное тело входящего вебхука
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    // Истекло время ожидания финального статуса при опросе счета
+    #[error("polling timed out waiting for a final status")]
+    Timeout,
+}
 
-fn generate_signature(payload_str: &str, api_key: &str) -> Result<String, CryptomusError> {
+pub(crate) fn generate_signature(payload_str: &str, api_key: &str) -> Result<String, CryptomusError> {
     if api_key.is_empty() {
-        return Err("missing api key".into());
+        return Err(CryptomusError::MissingApiKey);
     }
     let encoded_payload = base64::Engine::encode(
         &base64::engine::general_purpose::STANDARD,
@@ -75,6 +119,217 @@ pub struct CreateInvoiceRequest {
     pub is_refresh: Option<bool>, // Обновить истекший счет? (default: false)
 }
 
+impl CreateInvoiceRequest {
+    /// Начинает построение запроса через [`CreateInvoiceRequestBuilder`].
+    ///
+    /// Обязательные поля (`amount`, `currency`, `order_id`) проверяются в
+    /// [`CreateInvoiceRequestBuilder::build`], остальные по умолчанию `None`.
+    #[must_use]
+    pub fn builder() -> CreateInvoiceRequestBuilder {
+        CreateInvoiceRequestBuilder::default()
+    }
+}
+
+// Билдер для CreateInvoiceRequest — избавляет от перечисления всех
+// необязательных полей как `None` при создании счета.
+#[derive(Debug, Clone, Default)]
+pub struct CreateInvoiceRequestBuilder {
+    amount: Option<String>,
+    currency: Option<String>,
+    order_id: Option<String>,
+    network: Option<String>,
+    url_return: Option<String>,
+    url_success: Option<String>,
+    url_callback: Option<String>,
+    is_payment_multiple: Option<bool>,
+    lifetime: Option<i64>,
+    to_currency: Option<String>,
+    subtract: Option<i64>,
+    accuracy_payment_percent: Option<f64>,
+    additional_data: Option<String>,
+    currencies: Option<Vec<CurrencyNetwork>>,
+    except_currencies: Option<Vec<CurrencyNetwork>>,
+    course_source: Option<String>,
+    from_referral_code: Option<String>,
+    discount_percent: Option<i64>,
+    is_refresh: Option<bool>,
+}
+
+impl CreateInvoiceRequestBuilder {
+    /// Сумма к оплате (обязательно).
+    #[must_use]
+    pub fn amount(mut self, amount: impl Into<String>) -> Self {
+        self.amount = Some(amount.into());
+        self
+    }
+
+    /// Код валюты счета (обязательно).
+    #[must_use]
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    /// Уникальный ID заказа в вашей системе (обязательно).
+    #[must_use]
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    /// Код сети (блокчейна).
+    #[must_use]
+    pub fn network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    /// URL для возврата до оплаты.
+    #[must_use]
+    pub fn url_return(mut self, url_return: impl Into<String>) -> Self {
+        self.url_return = Some(url_return.into());
+        self
+    }
+
+    /// URL для возврата после успешной оплаты.
+    #[must_use]
+    pub fn url_success(mut self, url_success: impl Into<String>) -> Self {
+        self.url_success = Some(url_success.into());
+        self
+    }
+
+    /// URL для вебхуков.
+    #[must_use]
+    pub fn url_callback(mut self, url_callback: impl Into<String>) -> Self {
+        self.url_callback = Some(url_callback.into());
+        self
+    }
+
+    /// Разрешить доплату.
+    #[must_use]
+    pub fn is_payment_multiple(mut self, is_payment_multiple: bool) -> Self {
+        self.is_payment_multiple = Some(is_payment_multiple);
+        self
+    }
+
+    /// Время жизни счета в секундах (300-43200).
+    #[must_use]
+    pub fn lifetime(mut self, lifetime: i64) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Целевая криптовалюта для конвертации.
+    #[must_use]
+    pub fn to_currency(mut self, to_currency: impl Into<String>) -> Self {
+        self.to_currency = Some(to_currency.into());
+        self
+    }
+
+    /// Процент комиссии, взимаемый с клиента (0-100).
+    #[must_use]
+    pub fn subtract(mut self, subtract: i64) -> Self {
+        self.subtract = Some(subtract);
+        self
+    }
+
+    /// Допустимая погрешность оплаты в % (0-5).
+    #[must_use]
+    pub fn accuracy_payment_percent(mut self, accuracy_payment_percent: f64) -> Self {
+        self.accuracy_payment_percent = Some(accuracy_payment_percent);
+        self
+    }
+
+    /// Дополнительные данные (до 255 символов).
+    #[must_use]
+    pub fn additional_data(mut self, additional_data: impl Into<String>) -> Self {
+        self.additional_data = Some(additional_data.into());
+        self
+    }
+
+    /// Список разрешенных валют/сетей.
+    #[must_use]
+    pub fn currencies(mut self, currencies: Vec<CurrencyNetwork>) -> Self {
+        self.currencies = Some(currencies);
+        self
+    }
+
+    /// Список исключенных валют/сетей.
+    #[must_use]
+    pub fn except_currencies(mut self, except_currencies: Vec<CurrencyNetwork>) -> Self {
+        self.except_currencies = Some(except_currencies);
+        self
+    }
+
+    /// Источник курса ("Binance", "Kucoin", etc.).
+    #[must_use]
+    pub fn course_source(mut self, course_source: impl Into<String>) -> Self {
+        self.course_source = Some(course_source.into());
+        self
+    }
+
+    /// Реферальный код.
+    #[must_use]
+    pub fn from_referral_code(mut self, from_referral_code: impl Into<String>) -> Self {
+        self.from_referral_code = Some(from_referral_code.into());
+        self
+    }
+
+    /// Скидка (+) или доп. комиссия (-) в % (-99-100).
+    #[must_use]
+    pub fn discount_percent(mut self, discount_percent: i64) -> Self {
+        self.discount_percent = Some(discount_percent);
+        self
+    }
+
+    /// Обновить истекший счет.
+    #[must_use]
+    pub fn is_refresh(mut self, is_refresh: bool) -> Self {
+        self.is_refresh = Some(is_refresh);
+        self
+    }
+
+    /// Собирает [`CreateInvoiceRequest`], проверяя обязательные поля.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает [`CryptomusError::InvalidRequest`], если не заданы `amount`,
+    /// `currency` или `order_id`.
+    pub fn build(self) -> Result<CreateInvoiceRequest, CryptomusError> {
+        let amount = self
+            .amount
+            .ok_or_else(|| CryptomusError::InvalidRequest("не задано поле amount".to_string()))?;
+        let currency = self
+            .currency
+            .ok_or_else(|| CryptomusError::InvalidRequest("не задано поле currency".to_string()))?;
+        let order_id = self
+            .order_id
+            .ok_or_else(|| CryptomusError::InvalidRequest("не задано поле order_id".to_string()))?;
+
+        Ok(CreateInvoiceRequest {
+            amount,
+            currency,
+            order_id,
+            network: self.network,
+            url_return: self.url_return,
+            url_success: self.url_success,
+            url_callback: self.url_callback,
+            is_payment_multiple: self.is_payment_multiple,
+            lifetime: self.lifetime,
+            to_currency: self.to_currency,
+            subtract: self.subtract,
+            accuracy_payment_percent: self.accuracy_payment_percent,
+            additional_data: self.additional_data,
+            currencies: self.currencies,
+            except_currencies: self.except_currencies,
+            course_source: self.course_source,
+            from_referral_code: self.from_referral_code,
+            discount_percent: self.discount_percent,
+            is_refresh: self.is_refresh,
+        })
+    }
+}
+
 // Запрос информации о счете
 #[derive(Serialize, Debug, Clone)]
 pub struct InvoiceInfoRequest {
@@ -84,6 +339,49 @@ pub struct InvoiceInfoRequest {
     pub order_id: Option<String>, // ID заказа
 }
 
+// Запрос на возврат средств
+#[derive(Serialize, Debug, Clone)]
+pub struct RefundRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>, // UUID счета
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>, // ID заказа
+    pub address: String,  // Адрес для возврата средств
+    pub is_subtract: bool, // Списывать ли комиссию сети с суммы возврата
+}
+
+// Запрос на создание статического кошелька
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateWalletRequest {
+    pub currency: String, // Код валюты
+    pub network: String,  // Код сети (блокчейна)
+    pub order_id: String, // Уникальный ID в вашей системе
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_callback: Option<String>, // URL для вебхуков по этому кошельку
+}
+
+// Пустое тело запроса для эндпоинтов без параметров (balance, services).
+// Cryptomus подписывает такие запросы над пустым массивом `[]` (результат
+// PHP `json_encode([])`), а не над объектом `{}`, поэтому тип сериализуется
+// в пустой массив — иначе серверная подпись не совпадет.
+#[derive(Serialize, Debug, Clone)]
+struct EmptyRequest(Vec<()>);
+
+impl EmptyRequest {
+    fn new() -> Self {
+        EmptyRequest(Vec::new())
+    }
+}
+
+// Запрос на повторную отправку вебхука по счету
+#[derive(Serialize, Debug, Clone)]
+pub struct ResendWebhookRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>, // UUID счета
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>, // ID заказа
+}
+
 // --- Структуры ответов ---
 
 // Общая обертка для ответа API Cryptomus
@@ -132,6 +430,21 @@ impl PaymentStatus {
         let json_string = serde_json::to_string(&self)?;
         Ok(json_string.trim_matches('"').to_string())
     }
+
+    /// Возвращает `true`, если статус терминальный и дальше не изменится.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            PaymentStatus::Paid
+                | PaymentStatus::PaidOver
+                | PaymentStatus::Fail
+                | PaymentStatus::Cancel
+                | PaymentStatus::SystemFail
+                | PaymentStatus::RefundFail
+                | PaymentStatus::RefundPaid
+        )
+    }
 }
 
 // Структура ответа для счета (invoice)
@@ -175,7 +488,9 @@ pub struct InvoiceResponse {
 }
 
 // Десериализатор для полей, которые могут быть null или пустой строкой, но должны быть Option<String>
-fn deserialize_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+pub(crate) fn deserialize_optional_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
@@ -183,6 +498,63 @@ where
     Ok(s.filter(|val| !val.is_empty()))
 }
 
+// Баланс по одной валюте/сети
+#[derive(Deserialize, Debug, Clone)]
+pub struct BalanceCurrency {
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub uuid: Option<String>,
+    pub balance: String,       // Доступный баланс
+    pub currency_code: String, // Код валюты
+}
+
+// Балансы мерчанта и пользователя
+#[derive(Deserialize, Debug, Clone)]
+pub struct Balances {
+    pub merchant: Vec<BalanceCurrency>,
+    pub user: Vec<BalanceCurrency>,
+}
+
+// Элемент ответа баланса
+#[derive(Deserialize, Debug, Clone)]
+pub struct BalanceItem {
+    pub balance: Balances,
+}
+
+// Лимиты по платежной услуге
+#[derive(Deserialize, Debug, Clone)]
+pub struct ServiceLimit {
+    pub min_amount: String,
+    pub max_amount: String,
+}
+
+// Комиссия по платежной услуге
+#[derive(Deserialize, Debug, Clone)]
+pub struct ServiceCommission {
+    pub fee_amount: String,
+    pub percent: String,
+}
+
+// Доступная пара валюта/сеть с лимитами и комиссией
+#[derive(Deserialize, Debug, Clone)]
+pub struct PaymentService {
+    pub network: String,
+    pub currency: String,
+    pub is_available: bool,
+    pub limit: ServiceLimit,
+    pub commission: ServiceCommission,
+}
+
+// Ответ на создание статического кошелька
+#[derive(Deserialize, Debug, Clone)]
+pub struct StaticWalletResponse {
+    pub wallet_uuid: String,
+    pub uuid: String,
+    pub address: String, // Постоянный адрес для приема платежей
+    pub network: String,
+    pub currency: String,
+    pub url: String, // URL страницы оплаты
+}
+
 // --- Клиент Cryptomus ---
 
 #[derive(Clone)]
@@ -190,6 +562,7 @@ pub struct CryptomusClient {
     client: ReqwestClient,
     merchant_id: String,
     api_key: String, // Ключ для ПРИЕМА платежей (Payment API Key)
+    payout_api_key: Option<String>, // Ключ для ВЫПЛАТ (Payout API Key)
     base_url: String,
 }
 
@@ -209,6 +582,7 @@ impl CryptomusClient {
                 .expect("Не удалось создать HTTP клиент"),
             merchant_id,
             api_key,
+            payout_api_key: None,
             base_url: CRYPTOMUS_API_BASE_URL.to_string(),
         }
     }
@@ -220,17 +594,39 @@ impl CryptomusClient {
         self
     }
 
-    // Внутренний метод для отправки запросов
+    /// Задает Payout API Key, необходимый для операций выплат.
+    ///
+    /// Выплаты подписываются отдельным ключом, а не Payment API Key, поэтому
+    /// методы `create_payout`, `get_payout_info` и `get_payout_history`
+    /// требуют предварительной установки этого ключа.
+    #[must_use]
+    pub fn with_payout_api_key(mut self, payout_api_key: String) -> Self {
+        self.payout_api_key = Some(payout_api_key);
+        self
+    }
+
+    // Внутренний метод для отправки запросов (подпись Payment ключом)
     async fn send_request<T: Serialize, R: for<'de> Deserialize<'de>>(
         &self,
         endpoint: &str,
         payload: &T,
+    ) -> Result<R, CryptomusError> {
+        self.send_request_signed(endpoint, payload, &self.api_key)
+            .await
+    }
+
+    // Отправка запроса с подписью произвольным ключом (Payment или Payout)
+    async fn send_request_signed<T: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        payload: &T,
+        api_key: &str,
     ) -> Result<R, CryptomusError> {
         let url = format!("{}{}", self.base_url, endpoint);
 
         let payload_str = serde_json::to_string(payload)?;
 
-        let sign = generate_signature(&payload_str, &self.api_key)?;
+        let sign = generate_signature(&payload_str, api_key)?;
 
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
@@ -256,10 +652,14 @@ impl CryptomusClient {
 
             match serde_json::from_str::<GenericCryptomusResponse<()>>(&response_text) {
                 Ok(err_resp) => {
-                    return Err(err_resp.message.unwrap().into());
+                    return Err(CryptomusError::Api {
+                        state: err_resp.state,
+                        message: err_resp.message,
+                        errors: err_resp.errors,
+                    });
                 }
                 Err(_) => {
-                    return Err(response_text.into());
+                    return Err(CryptomusError::InvalidRequest(response_text));
                 }
             }
         }
@@ -271,11 +671,15 @@ impl CryptomusClient {
 
         if parsed_response.state == 0 {
             let Some(f) = parsed_response.result else {
-                return Err("dfdf".into());
+                return Err(CryptomusError::MissingResult);
             };
             Ok(f)
         } else {
-            Err(parsed_response.message.unwrap().into())
+            Err(CryptomusError::Api {
+                state: parsed_response.state,
+                message: parsed_response.message,
+                errors: parsed_response.errors,
+            })
         }
     }
 
@@ -302,16 +706,150 @@ impl CryptomusClient {
     ) -> Result<InvoiceResponse, CryptomusError> {
         // Проверка, что хотя бы одно поле заполнено
         if request.uuid.is_none() && request.order_id.is_none() {
-            return Err("Необходимо указать uuid или order_id".into());
+            return Err(CryptomusError::InvalidRequest(
+                "необходимо указать uuid или order_id".to_string(),
+            ));
         }
         self.send_request("payment/info", request).await
     }
 
+    /// Выполняет возврат средств по счету на указанный адрес.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку, если не указан ни `uuid`, ни `order_id`, либо при
+    /// неуспешном запросе к API.
+    pub async fn refund(
+        &self,
+        request: &RefundRequest,
+    ) -> Result<serde_json::Value, CryptomusError> {
+        if request.uuid.is_none() && request.order_id.is_none() {
+            return Err(CryptomusError::InvalidRequest(
+                "необходимо указать uuid или order_id".to_string(),
+            ));
+        }
+        self.send_request("payment/refund", request).await
+    }
+
+    /// Возвращает клиенту сумму переплаты по счету (overpaid invoice).
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку, если не указан ни `uuid`, ни `order_id`, либо при
+    /// неуспешном запросе к API.
+    pub async fn refund_from_paid(
+        &self,
+        request: &RefundRequest,
+    ) -> Result<serde_json::Value, CryptomusError> {
+        if request.uuid.is_none() && request.order_id.is_none() {
+            return Err(CryptomusError::InvalidRequest(
+                "необходимо указать uuid или order_id".to_string(),
+            ));
+        }
+        self.send_request("payment/refund/paid", request).await
+    }
+
+    /// Повторно отправляет вебхук с текущим статусом счета.
+    ///
+    /// Используется для сверки, когда исходный колбэк был потерян.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку, если не указан ни `uuid`, ни `order_id`, либо при
+    /// неуспешном запросе к API.
+    pub async fn resend_webhook(
+        &self,
+        request: &ResendWebhookRequest,
+    ) -> Result<serde_json::Value, CryptomusError> {
+        if request.uuid.is_none() && request.order_id.is_none() {
+            return Err(CryptomusError::InvalidRequest(
+                "необходимо указать uuid или order_id".to_string(),
+            ));
+        }
+        self.send_request("payment/resend", request).await
+    }
+
+    /// Возвращает балансы мерчанта и пользователя по валютам/сетям.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку API/транспорта при неуспешном запросе.
+    pub async fn get_balance(&self) -> Result<Vec<BalanceItem>, CryptomusError> {
+        self.send_request("balance", &EmptyRequest::new()).await
+    }
+
+    /// Возвращает список доступных пар валюта/сеть с лимитами и комиссией.
+    ///
+    /// Полезно для предварительной валидации [`CurrencyNetwork`] перед вызовом
+    /// [`create_invoice`](Self::create_invoice).
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку API/транспорта при неуспешном запросе.
+    pub async fn get_payment_services(&self) -> Result<Vec<PaymentService>, CryptomusError> {
+        self.send_request("payment/services", &EmptyRequest::new())
+            .await
+    }
+
+    /// Создает статический кошелек с постоянным адресом для приема платежей.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку API/транспорта при неуспешном запросе.
+    pub async fn create_static_wallet(
+        &self,
+        request: &CreateWalletRequest,
+    ) -> Result<StaticWalletResponse, CryptomusError> {
+        self.send_request("wallet", request).await
+    }
+
     // --- Другие методы API можно добавить здесь по аналогии ---
-    // Например, для получения списка услуг, баланса, создания статических кошельков, выплат и т.д.
     // Не забывайте проверять, какой API ключ нужен для каждого типа операций (Payment или Payout).
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_request_serializes_to_array() {
+        // Cryptomus подписывает параметрless-запросы над `[]`, а не `{}`.
+        assert_eq!(serde_json::to_string(&EmptyRequest::new()).unwrap(), "[]");
+    }
+
+    #[test]
+    fn builder_enforces_required_fields() {
+        let err = CreateInvoiceRequest::builder()
+            .currency("USD")
+            .order_id("order-1")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, CryptomusError::InvalidRequest(_)));
+    }
+
+    #[test]
+    fn builder_builds_with_required_fields() {
+        let request = CreateInvoiceRequest::builder()
+            .amount("10.00")
+            .currency("USD")
+            .order_id("order-1")
+            .url_callback("https://example.com/cb")
+            .build()
+            .expect("обязательные поля заданы");
+        assert_eq!(request.amount, "10.00");
+        assert_eq!(request.url_callback.as_deref(), Some("https://example.com/cb"));
+        assert_eq!(request.network, None);
+    }
+
+    #[test]
+    fn payment_status_terminal_classification() {
+        assert!(PaymentStatus::Paid.is_terminal());
+        assert!(PaymentStatus::Cancel.is_terminal());
+        assert!(!PaymentStatus::Process.is_terminal());
+        assert!(!PaymentStatus::Check.is_terminal());
+    }
+}
+
 // --- Пример использования ---
 // Разместите этот код в main.rs или тестах
 
@@ -364,7 +902,7 @@ impl CryptomusClient {
 //         Err(e) => {
 //             eprintln!("Ошибка при создании счета: {}", e);
 //             // Печать деталей ошибки API, если они есть
-//             if let CryptomusError::ApiError { state: _, message, errors } = e {
+//             if let CryptomusError::Api { state: _, message, errors } = e {
 //                 eprintln!("  Сообщение API: {:?}", message);
 //                 eprintln!("  Ошибки валидации API: {:?}", errors);
 //             }
@@ -396,7 +934,7 @@ impl CryptomusClient {
 //         }
 //         Err(e) => {
 //             eprintln!("Ошибка при получении информации о счете: {}", e);
-//             if let CryptomusError::ApiError { state: _, message, errors } = e {
+//             if let CryptomusError::Api { state: _, message, errors } = e {
 //                 eprintln!("  Сообщение API: {:?}", message);
 //                 eprintln!("  Ошибки валидации API: {:?}", errors);
 //             }
@@ -0,0 +1,142 @@
+//! Payout API — вывод средств с баланса мерчанта.
+//!
+//! Выплаты подписываются отдельным Payout API Key (а не Payment API Key),
+//! который задается через [`CryptomusClient::with_payout_api_key`]. Все методы
+//! возвращают [`CryptomusError::MissingApiKey`], если ключ выплат не установлен.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CryptomusClient, CryptomusError, PaymentStatus, deserialize_optional_string};
+
+// Запрос на создание выплаты
+#[derive(Serialize, Debug, Clone)]
+pub struct CreatePayoutRequest {
+    pub amount: String,   // Сумма выплаты
+    pub currency: String, // Код валюты выплаты
+    pub network: String,  // Сеть (блокчейн), в которой выполняется выплата
+    pub order_id: String, // Уникальный ID выплаты в вашей системе
+    pub address: String,  // Адрес получателя
+    pub is_subtract: bool, // Списывать ли комиссию сети с суммы выплаты
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_callback: Option<String>, // URL для вебхуков о статусе выплаты
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_source: Option<String>, // Источник курса ("Binance", "Kucoin", etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>, // Приоритет комиссии сети ("recommended", "economy", etc.)
+}
+
+// Запрос информации о выплате
+#[derive(Serialize, Debug, Clone)]
+pub struct PayoutInfoRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>, // UUID выплаты
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>, // ID выплаты в вашей системе
+}
+
+// Запрос истории выплат
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct PayoutHistoryRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<String>, // Начало периода (Y-m-d H:i:s)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<String>, // Конец периода (Y-m-d H:i:s)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>, // Курсор постраничной навигации
+}
+
+// Ответ с информацией о выплате
+#[derive(Deserialize, Debug, Clone)]
+pub struct PayoutResponse {
+    pub uuid: String,
+    pub order_id: String,
+    pub amount: String,   // Сумма выплаты
+    pub currency: String, // Валюта выплаты
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub network: Option<String>, // Сеть
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub address: Option<String>, // Адрес получателя
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub txid: Option<String>, // Хеш транзакции
+    pub status: PaymentStatus, // Статус выплаты
+    pub is_final: bool, // Финализирована ли выплата
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub balance: Option<String>, // Остаток баланса мерчанта после выплаты
+}
+
+// Постраничная навигация в истории выплат
+#[derive(Deserialize, Debug, Clone)]
+pub struct Paginate {
+    pub count: i64,
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub has_pages: Option<String>,
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub next_cursor: Option<String>,
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    pub previous_cursor: Option<String>,
+    pub per_page: i64,
+}
+
+// Ответ с историей выплат
+#[derive(Deserialize, Debug, Clone)]
+pub struct PayoutHistoryResponse {
+    pub merchant_uuid: String,
+    pub items: Vec<PayoutResponse>,
+    pub paginate: Paginate,
+}
+
+impl CryptomusClient {
+    // Возвращает Payout API Key или ошибку, если он не задан.
+    fn payout_key(&self) -> Result<&str, CryptomusError> {
+        self.payout_api_key
+            .as_deref()
+            .ok_or(CryptomusError::MissingApiKey)
+    }
+
+    /// Создает выплату на указанный адрес.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает [`CryptomusError::MissingApiKey`], если Payout API Key не
+    /// установлен, либо ошибку API/транспорта при неуспешном запросе.
+    pub async fn create_payout(
+        &self,
+        request: &CreatePayoutRequest,
+    ) -> Result<PayoutResponse, CryptomusError> {
+        let key = self.payout_key()?;
+        self.send_request_signed("payout", request, key).await
+    }
+
+    /// Возвращает информацию о выплате по `uuid` или `order_id`.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает ошибку, если не указан ни `uuid`, ни `order_id`, если Payout
+    /// API Key не установлен, либо при неуспешном запросе к API.
+    pub async fn get_payout_info(
+        &self,
+        request: &PayoutInfoRequest,
+    ) -> Result<PayoutResponse, CryptomusError> {
+        if request.uuid.is_none() && request.order_id.is_none() {
+            return Err(CryptomusError::InvalidRequest(
+                "необходимо указать uuid или order_id".to_string(),
+            ));
+        }
+        let key = self.payout_key()?;
+        self.send_request_signed("payout/info", request, key).await
+    }
+
+    /// Возвращает историю выплат за период с постраничной навигацией.
+    ///
+    /// # Errors
+    ///
+    /// Возвращает [`CryptomusError::MissingApiKey`], если Payout API Key не
+    /// установлен, либо ошибку API/транспорта при неуспешном запросе.
+    pub async fn get_payout_history(
+        &self,
+        request: &PayoutHistoryRequest,
+    ) -> Result<PayoutHistoryResponse, CryptomusError> {
+        let key = self.payout_key()?;
+        self.send_request_signed("payout/list", request, key).await
+    }
+}
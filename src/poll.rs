@@ -0,0 +1,72 @@
+//! Опрос счета до достижения финального статуса.
+//!
+//! Вместо ручного цикла `sleep` + `get_invoice_info` (см. закомментированный
+//! пример) [`CryptomusClient::wait_until_final`] опрашивает `payment/info`,
+//! пока счет не станет финальным, и возвращает его одним `await`.
+
+use std::time::{Duration, Instant};
+
+use crate::{CryptomusClient, CryptomusError, InvoiceInfoRequest, InvoiceResponse};
+
+// Верхняя граница интервала опроса при экспоненциальном росте.
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+// Параметры опроса счета
+#[derive(Debug, Clone)]
+pub struct PollConfig {
+    pub interval: Duration, // Начальный интервал между опросами
+    pub timeout: Duration,  // Максимальное суммарное время ожидания
+    pub max_attempts: u32,  // Максимум попыток (0 — без ограничения)
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        PollConfig {
+            interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(3600),
+            max_attempts: 0,
+        }
+    }
+}
+
+impl CryptomusClient {
+    /// Опрашивает счет, пока он не станет финальным, и возвращает его.
+    ///
+    /// Опрос прекращается, как только `InvoiceResponse.is_final == true` либо
+    /// статус платежа терминальный (см. [`PaymentStatus::is_terminal`]). Между
+    /// опросами интервал растет экспоненциально до [`MAX_POLL_INTERVAL`].
+    ///
+    /// [`PaymentStatus::is_terminal`]: crate::PaymentStatus::is_terminal
+    ///
+    /// # Errors
+    ///
+    /// Возвращает [`CryptomusError::Timeout`] при исчерпании `timeout` или
+    /// `max_attempts`, а также ошибки API/транспорта при опросе.
+    pub async fn wait_until_final(
+        &self,
+        request: &InvoiceInfoRequest,
+        config: PollConfig,
+    ) -> Result<InvoiceResponse, CryptomusError> {
+        let start = Instant::now();
+        let mut interval = config.interval;
+        let mut attempt: u32 = 0;
+
+        loop {
+            let invoice = self.get_invoice_info(request).await?;
+            if invoice.is_final || invoice.payment_status.is_terminal() {
+                return Ok(invoice);
+            }
+
+            attempt += 1;
+            if config.max_attempts != 0 && attempt >= config.max_attempts {
+                return Err(CryptomusError::Timeout);
+            }
+            if start.elapsed() + interval > config.timeout {
+                return Err(CryptomusError::Timeout);
+            }
+
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Проверка и разбор вебхуков Cryptomus.
+//!
+//! Cryptomus отправляет POST-запрос на `url_callback`, указанный в
+//! [`CreateInvoiceRequest`](crate::CreateInvoiceRequest), при каждом изменении
+//! статуса платежа. Тело запроса содержит поле `sign`, которое считается точно
+//! так же, как и исходящий заголовок в [`generate_signature`](crate::generate_signature):
+//! MD5 от `base64(json_body) + api_key`. Перед тем как доверять содержимому
+//! колбэка, подпись необходимо пересчитать и сравнить.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CryptomusClient, CryptomusError, PaymentStatus, generate_signature};
+
+// Разобранное и проверенное тело вебхука
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebhookPayload {
+    pub uuid: String,
+    pub order_id: String,
+    pub amount: String,             // Сумма счета
+    #[serde(default, deserialize_with = "crate::deserialize_optional_string")]
+    pub payment_amount: Option<String>, // Сколько фактически оплачено (null до оплаты)
+    #[serde(default, deserialize_with = "crate::deserialize_optional_string")]
+    pub payer_currency: Option<String>, // Валюта, в которой платил клиент (null до оплаты)
+    #[serde(default)]
+    pub network: Option<String>,    // Сеть (блокчейн)
+    #[serde(default)]
+    pub txid: Option<String>,       // Хеш транзакции
+    pub status: PaymentStatus,      // Текущий статус платежа
+    pub is_final: bool,             // Финализирован ли платеж
+    #[serde(default)]
+    pub additional_data: Option<String>, // Переданные при создании доп. данные
+    pub sign: String,               // Подпись, которой подписано тело запроса
+}
+
+impl CryptomusClient {
+    /// Проверяет подпись входящего вебхука и возвращает разобранное тело.
+    ///
+    /// Из JSON извлекается поле `sign`, член `sign` вырезается из исходной
+    /// строки тела (порядок и экранирование остальных полей сохраняются, как
+    /// их подписывает Cryptomus), и подпись пересчитывается с Payment API ключом мерчанта. Сравнение
+    /// выполняется за постоянное время, и только после успешной проверки тело
+    /// десериализуется в [`WebhookPayload`].
+    ///
+    /// # Errors
+    ///
+    /// Возвращает [`CryptomusError::SignatureMismatch`], если подпись не
+    /// совпадает, либо ошибку (де)сериализации при некорректном теле запроса.
+    pub fn verify_webhook(&self, raw_body: &str) -> Result<WebhookPayload, CryptomusError> {
+        // Разбираем тело в произвольный объект, чтобы достать подпись.
+        let value: serde_json::Value = serde_json::from_str(raw_body)?;
+        let object = value.as_object().ok_or_else(|| {
+            CryptomusError::InvalidRequest("тело вебхука не является JSON-объектом".to_string())
+        })?;
+
+        let provided_sign = object
+            .get(SIGN_FIELD)
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| {
+                CryptomusError::InvalidRequest("в теле вебхука отсутствует поле sign".to_string())
+            })?;
+
+        // Cryptomus подписывает тело с удаленным полем `sign`. Поле убираем
+        // прямо из исходной строки, а не пересериализуем `Value`: serde_json
+        // без фичи `preserve_order` сортирует ключи по алфавиту, и
+        // пересчитанный MD5 не совпал бы с присланным. Работа со строкой
+        // сохраняет и порядок ключей, и PHP-экранирование (`json_encode`
+        // экранирует `/` как `\/`), поэтому тело совпадает байт в байт.
+        let unsigned_body = strip_sign_member(raw_body, provided_sign).ok_or_else(|| {
+            CryptomusError::InvalidRequest("не удалось выделить подписанное тело".to_string())
+        })?;
+        let expected_sign = generate_signature(&unsigned_body, &self.api_key)?;
+
+        if !constant_time_eq(provided_sign.as_bytes(), expected_sign.as_bytes()) {
+            return Err(CryptomusError::SignatureMismatch);
+        }
+
+        Ok(serde_json::from_str(raw_body)?)
+    }
+}
+
+const SIGN_FIELD: &str = "sign";
+
+// Удаляет член `"sign":"..."` из исходной строки тела, сохраняя порядок и
+// экранирование остальных полей. Значение подписи — это hex-строка MD5 без
+// кавычек и спецсимволов внутри, поэтому член можно найти и вырезать из строки,
+// не нарушая остальной JSON. Вместе с членом убирается один соседний запятой-
+// разделитель, чтобы результат остался корректным JSON-объектом.
+fn strip_sign_member(raw: &str, sign_value: &str) -> Option<String> {
+    let needle = format!("\"{SIGN_FIELD}\":\"{sign_value}\"");
+    let pos = raw.find(&needle)?;
+    let mut start = pos;
+    let mut end = pos + needle.len();
+    let bytes = raw.as_bytes();
+    if end < bytes.len() && bytes[end] == b',' {
+        end += 1;
+    } else if start > 0 && bytes[start - 1] == b',' {
+        start -= 1;
+    }
+    let mut unsigned = String::with_capacity(raw.len() - (end - start));
+    unsigned.push_str(&raw[..start]);
+    unsigned.push_str(&raw[end..]);
+    Some(unsigned)
+}
+
+// Сравнение байтовых срезов за постоянное время, чтобы не давать таймингового
+// канала при проверке подписи.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const API_KEY: &str = "test-payment-api-key";
+
+    // Тело в том порядке, в котором его подписывает Cryptomus (с PHP-экранированием
+    // слэшей). Подпись считается именно по этой строке.
+    const SIGNED_BODY: &str = r#"{"uuid":"8b03432e-385b-4670-8d06-064591096795","order_id":"order-1","amount":"10.00","payment_amount":"10.00","payer_currency":"USDT","network":"tron","txid":"abc","status":"paid","is_final":true,"additional_data":"https:\/\/example.com\/cb"}"#;
+
+    fn signed_webhook(body: &str) -> String {
+        let sign = generate_signature(body, API_KEY).unwrap();
+        // Cryptomus добавляет `sign` последним полем.
+        format!("{}\"sign\":\"{sign}\"}}", body[..body.len() - 1].to_string() + ",")
+    }
+
+    #[test]
+    fn verify_webhook_accepts_valid_signature() {
+        let raw = signed_webhook(SIGNED_BODY);
+        let client = CryptomusClient::new("merchant".to_string(), API_KEY.to_string());
+        let payload = client.verify_webhook(&raw).expect("подпись должна совпасть");
+        assert_eq!(payload.order_id, "order-1");
+        assert_eq!(payload.status, PaymentStatus::Paid);
+        assert_eq!(payload.payment_amount.as_deref(), Some("10.00"));
+    }
+
+    #[test]
+    fn verify_webhook_rejects_tampered_body() {
+        let raw = signed_webhook(SIGNED_BODY).replace("\"amount\":\"10.00\"", "\"amount\":\"99.00\"");
+        let client = CryptomusClient::new("merchant".to_string(), API_KEY.to_string());
+        assert!(matches!(
+            client.verify_webhook(&raw),
+            Err(CryptomusError::SignatureMismatch)
+        ));
+    }
+
+    #[test]
+    fn verify_webhook_parses_pending_payload_with_nulls() {
+        // До оплаты Cryptomus присылает payment_amount/payer_currency как null.
+        let body = r#"{"uuid":"u","order_id":"order-2","amount":"10.00","payment_amount":null,"payer_currency":null,"status":"check","is_final":false}"#;
+        let raw = signed_webhook(body);
+        let client = CryptomusClient::new("merchant".to_string(), API_KEY.to_string());
+        let payload = client.verify_webhook(&raw).expect("pending вебхук должен разбираться");
+        assert_eq!(payload.payment_amount, None);
+        assert_eq!(payload.payer_currency, None);
+        assert!(!payload.is_final);
+    }
+}